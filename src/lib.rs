@@ -1,6 +1,6 @@
 use std::{
-    collections::HashMap,
-    time::{Duration, SystemTime},
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant, SystemTime},
 };
 
 /// A TimedSet that keeps a TTL for each of its elements.
@@ -19,11 +19,14 @@ use std::{
 pub struct TimedSet<T> {
     ttl: Duration,
     set: HashMap<T, SystemTime>,
+    max_len: Option<usize>,
+    order: VecDeque<T>,
+    wheel: Option<Wheel<T>>,
 }
 
 impl<T> TimedSet<T>
 where
-    T: std::hash::Hash + Eq,
+    T: std::hash::Hash + Eq + Clone,
 {
     /// Create a new TimedSet with a TTL of its elements. Here all the elements added into
     /// this TimedSet will inherit the TTL specified while initiating the TimedSet.
@@ -39,6 +42,131 @@ where
         Self {
             ttl,
             set: HashMap::new(),
+            max_len: None,
+            order: VecDeque::new(),
+            wheel: None,
+        }
+    }
+
+    /// Create a new TimedSet with a TTL of its elements and a maximum number of live
+    /// elements. Once the set grows past `max_len`, the oldest-inserted element is
+    /// evicted to make room, in addition to the usual TTL-based expiry.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut ts = TimedSet::with_capacity(Duration::from_secs(2), 2);
+    /// ts.add("element1");
+    /// ts.add("element2");
+    /// ts.add("element3");
+    /// assert!(!ts.contains(&"element1"));
+    /// assert!(ts.contains(&"element3"));
+    /// ```
+    pub fn with_capacity(ttl: Duration, max_len: usize) -> Self {
+        Self {
+            ttl,
+            set: HashMap::new(),
+            max_len: Some(max_len),
+            order: VecDeque::new(),
+            wheel: None,
+        }
+    }
+
+    /// Create a new TimedSet backed by a timer wheel instead of a pure lazy scan, so
+    /// pruning is proportional to the number of expired items rather than the whole
+    /// set. `slots` is the number of buckets in the wheel and `granularity` is the
+    /// duration each bucket spans; deadlines farther out than `granularity * slots`
+    /// are held in an overflow list until the wheel wraps around to them. The
+    /// `HashMap` stays the source of truth for `contains` — the wheel is just the
+    /// expiry schedule, advanced by calling `tick`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut ts = TimedSet::with_wheel(Duration::from_secs(2), 4, Duration::from_millis(500));
+    /// ts.add("element1");
+    /// assert!(ts.contains(&"element1"));
+    /// ```
+    pub fn with_wheel(ttl: Duration, slots: usize, granularity: Duration) -> Self {
+        Self {
+            ttl,
+            set: HashMap::new(),
+            max_len: None,
+            order: VecDeque::new(),
+            wheel: Some(Wheel::new(slots, granularity)),
+        }
+    }
+
+    /// Removes all entries whose deadline has already passed from the backing map,
+    /// so the set doesn't grow unbounded just because nobody looked them up. When a
+    /// timer wheel is configured, this drains only the buckets that have fully
+    /// elapsed, plus an exact-deadline check of the current bucket, instead of
+    /// scanning the whole map.
+    fn remove_expired(&mut self) {
+        if self.wheel.is_some() {
+            self.drain_wheel();
+        } else {
+            let now = SystemTime::now();
+            self.set.retain(|_, t| now < *t);
+        }
+        self.sync_order();
+    }
+
+    /// Drops any keys from `order` that are no longer in `set`. Without this, keys
+    /// that expire via TTL rather than get capacity-evicted would pile up in `order`
+    /// forever, since only `enforce_capacity`'s pop-front removes from it otherwise.
+    fn sync_order(&mut self) {
+        if self.max_len.is_some() {
+            let set = &self.set;
+            self.order.retain(|k| set.contains_key(k));
+        }
+    }
+
+    /// Advances the timer wheel to now and removes the keys of every slot that has
+    /// fully elapsed, re-checking each against its actual deadline in `set` since a
+    /// slot spans a whole `granularity` window. Keys in the current, still-open slot
+    /// are also checked against their exact deadline, so no expired key outlives
+    /// this call. A no-op when there's no wheel.
+    fn drain_wheel(&mut self) {
+        let now_instant = Instant::now();
+        let now_time = SystemTime::now();
+        if let Some(wheel) = self.wheel.as_mut() {
+            for key in wheel.tick(now_instant) {
+                if self.set.get(&key).is_some_and(|deadline| *deadline <= now_time) {
+                    self.set.remove(&key);
+                }
+            }
+            let current = (wheel.cursor % wheel.slots.len() as u64) as usize;
+            for key in &wheel.slots[current] {
+                if self.set.get(key).is_some_and(|deadline| *deadline <= now_time) {
+                    self.set.remove(key);
+                }
+            }
+        }
+    }
+
+    /// Reschedules `val`'s entry in the timer wheel to the given absolute `ttl`
+    /// from now. Called whenever an entry's deadline is refreshed in place, since
+    /// the wheel's original bucket placement is otherwise never revisited.
+    fn reschedule(&mut self, val: &T, ttl: Duration) {
+        if let Some(wheel) = self.wheel.as_mut() {
+            wheel.schedule(val.clone(), Instant::now() + ttl);
+        }
+    }
+
+    /// Evicts the oldest-inserted elements until the set is back within `max_len`.
+    fn enforce_capacity(&mut self) {
+        if let Some(max_len) = self.max_len {
+            while self.set.len() > max_len {
+                match self.order.pop_front() {
+                    Some(oldest) => {
+                        self.set.remove(&oldest);
+                    }
+                    None => break,
+                }
+            }
         }
     }
 
@@ -52,7 +180,8 @@ where
     /// ts.add("element1");
     /// ```
     pub fn add(&mut self, val: T) {
-        self.set.insert(val, SystemTime::now() + self.ttl);
+        let ttl = self.ttl;
+        self.add_with_ttl(val, ttl);
     }
 
     /// Add/Insert an element into the timed set
@@ -65,7 +194,40 @@ where
     /// ts.add_with_ttl("element1", Duration::from_secs(1));
     /// ```
     pub fn add_with_ttl(&mut self, val: T, ttl: Duration) {
-        self.set.insert(val, SystemTime::now() + ttl);
+        self.remove_expired();
+        let is_new = self
+            .set
+            .insert(val.clone(), SystemTime::now() + ttl)
+            .is_none();
+        if let Some(wheel) = self.wheel.as_mut() {
+            wheel.schedule(val.clone(), Instant::now() + ttl);
+        }
+        // Only track newly-inserted keys in `order` — re-adding an existing key must
+        // not duplicate it there, or `order` grows unbounded even though `set` stays
+        // within `max_len`.
+        if self.max_len.is_some() && is_new {
+            self.order.push_back(val);
+            self.enforce_capacity();
+        }
+    }
+
+    /// Advance the timer wheel to the current time, draining every slot that has
+    /// fully elapsed and removing its keys from the backing map. A no-op on sets
+    /// created without `with_wheel`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::{time::Duration, thread::sleep};
+    ///
+    /// let mut ts = TimedSet::with_wheel(Duration::from_millis(50), 4, Duration::from_millis(20));
+    /// ts.add("element1");
+    /// sleep(Duration::from_millis(100));
+    /// ts.tick();
+    /// assert!(!ts.contains(&"element1"));
+    /// ```
+    pub fn tick(&mut self) {
+        self.drain_wheel();
+        self.sync_order();
     }
 
     /// Check if an element is present in the TimedSet
@@ -78,21 +240,430 @@ where
     /// ts.add("element1");
     /// assert!(ts.contains(&"element1"));
     /// ```
-    pub fn contains(&self, val: &T) -> bool {
-        if let Some(s) = self.set.get(val) {
-            if SystemTime::now() < *s {
-                return true;
+    pub fn contains(&mut self, val: &T) -> bool {
+        self.remove_expired();
+        self.set.contains_key(val)
+    }
+
+    /// Check if an element is present and, if so, reset its deadline to `now + ttl`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::{time::Duration, thread::sleep};
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(2));
+    /// ts.add("element1");
+    /// sleep(Duration::from_secs(1));
+    /// assert!(ts.contains_and_refresh(&"element1"));
+    /// sleep(Duration::from_secs(1));
+    /// // still alive: the lookup above moved the deadline to 2 seconds after it
+    /// assert!(ts.contains(&"element1"));
+    /// ```
+    pub fn contains_and_refresh(&mut self, val: &T) -> bool {
+        self.remove_expired();
+        let ttl = self.ttl;
+        let refreshed = match self.set.get_mut(val) {
+            Some(t) => {
+                *t = SystemTime::now() + ttl;
+                true
+            }
+            None => false,
+        };
+        if refreshed {
+            self.reschedule(val, ttl);
+        }
+        refreshed
+    }
+
+    /// Look up a still-live element, refreshing its deadline like `contains_and_refresh`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(2));
+    /// ts.add("element1");
+    /// assert_eq!(ts.get(&"element1"), Some(&"element1"));
+    /// ```
+    pub fn get(&mut self, val: &T) -> Option<&T> {
+        self.remove_expired();
+        let ttl = self.ttl;
+        let refreshed = self.set.get_mut(val).is_some_and(|t| {
+            *t = SystemTime::now() + ttl;
+            true
+        });
+        if refreshed {
+            self.reschedule(val, ttl);
+        }
+        self.set.get_key_value(val).map(|(k, _)| k)
+    }
+
+    /// Re-arm an element's deadline to `now + ttl` without re-inserting it. A no-op
+    /// returning `false` if `val` isn't present.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(2));
+    /// ts.add("element1");
+    /// assert!(ts.touch(&"element1", Duration::from_secs(10)));
+    /// ```
+    pub fn touch(&mut self, val: &T, ttl: Duration) -> bool {
+        self.remove_expired();
+        let refreshed = match self.set.get_mut(val) {
+            Some(t) => {
+                *t = SystemTime::now() + ttl;
+                true
             }
+            None => false,
+        };
+        if refreshed {
+            self.reschedule(val, ttl);
         }
-        false
+        refreshed
+    }
+
+    /// Drop expired elements, then keep only the live ones for which `f` returns `true`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(2));
+    /// ts.add("keep_1");
+    /// ts.add("drop_1");
+    /// ts.retain(|v| v.starts_with("keep"));
+    /// assert!(ts.contains(&"keep_1"));
+    /// assert!(!ts.contains(&"drop_1"));
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.remove_expired();
+        self.set.retain(|k, _| f(k));
+        self.sync_order();
     }
 
     /// Iterator
-    pub fn iter(&self) -> Iter<'_, T> {
+    pub fn iter(&mut self) -> Iter<'_, T> {
+        self.remove_expired();
         Iter {
             set: self.set.iter().map(|(k, v)| (k, v)).collect(),
         }
     }
+
+    /// Iterator over the elements whose deadline has already passed — the
+    /// complement of `iter`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::{time::Duration, thread::sleep};
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(0));
+    /// ts.add("element1");
+    /// sleep(Duration::from_millis(10));
+    /// assert_eq!(ts.expired().count(), 1);
+    /// ```
+    pub fn expired(&self) -> impl Iterator<Item = &T> {
+        let now = SystemTime::now();
+        self.set
+            .iter()
+            .filter(move |(_, t)| now >= **t)
+            .map(|(k, _)| k)
+    }
+
+    /// Physically removes all expired entries and returns how many were dropped.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::{time::Duration, thread::sleep};
+    ///
+    /// let mut ts = TimedSet::new(Duration::from_secs(0));
+    /// ts.add("element1");
+    /// sleep(Duration::from_millis(10));
+    /// assert_eq!(ts.prune(), 1);
+    /// ```
+    pub fn prune(&mut self) -> usize {
+        let before = self.set.len();
+        self.remove_expired();
+        before - self.set.len()
+    }
+
+    /// Elements that are currently live in either `self` or `other`. An element
+    /// present in both inputs keeps the earlier of the two deadlines.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut a = TimedSet::new(Duration::from_secs(2));
+    /// a.add("x");
+    /// let mut b = TimedSet::new(Duration::from_secs(2));
+    /// b.add("y");
+    /// let mut u = a.union(&b);
+    /// assert!(u.contains(&"x") && u.contains(&"y"));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let now = SystemTime::now();
+        let mut result = Self::new(self.ttl);
+        for (k, t) in self.set.iter().filter(|(_, t)| now < **t) {
+            result.set.insert(k.clone(), *t);
+        }
+        for (k, t) in other.set.iter().filter(|(_, t)| now < **t) {
+            result
+                .set
+                .entry(k.clone())
+                .and_modify(|e| *e = std::cmp::min(*e, *t))
+                .or_insert(*t);
+        }
+        result
+    }
+
+    /// Elements that are currently live in both `self` and `other`, each keyed to
+    /// `min(expiry_self, expiry_other)`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut a = TimedSet::new(Duration::from_secs(2));
+    /// a.add("x");
+    /// let mut b = TimedSet::new(Duration::from_secs(2));
+    /// b.add("x");
+    /// let mut i = a.intersection(&b);
+    /// assert!(i.contains(&"x"));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let now = SystemTime::now();
+        let mut result = Self::new(self.ttl);
+        for (k, t) in self.set.iter().filter(|(_, t)| now < **t) {
+            if let Some(ot) = other.set.get(k).filter(|ot| now < **ot) {
+                result.set.insert(k.clone(), std::cmp::min(*t, *ot));
+            }
+        }
+        result
+    }
+
+    /// Elements that are currently live in `self` but not (currently live) in `other`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut a = TimedSet::new(Duration::from_secs(2));
+    /// a.add("x");
+    /// a.add("y");
+    /// let mut b = TimedSet::new(Duration::from_secs(2));
+    /// b.add("y");
+    /// let mut d = a.difference(&b);
+    /// assert!(d.contains(&"x") && !d.contains(&"y"));
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let now = SystemTime::now();
+        let mut result = Self::new(self.ttl);
+        for (k, t) in self.set.iter().filter(|(_, t)| now < **t) {
+            let other_live = other.set.get(k).is_some_and(|ot| now < *ot);
+            if !other_live {
+                result.set.insert(k.clone(), *t);
+            }
+        }
+        result
+    }
+
+    /// Elements that are currently live in exactly one of `self` or `other`.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::TimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let mut a = TimedSet::new(Duration::from_secs(2));
+    /// a.add("x");
+    /// a.add("y");
+    /// let mut b = TimedSet::new(Duration::from_secs(2));
+    /// b.add("y");
+    /// b.add("z");
+    /// let mut d = a.symmetric_difference(&b);
+    /// assert!(d.contains(&"x") && d.contains(&"z") && !d.contains(&"y"));
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut result = self.difference(other);
+        for (k, t) in other.difference(self).set {
+            result.set.insert(k, t);
+        }
+        result
+    }
+}
+
+/// A `TimedSet` variant that quantizes expiry into coarse "generations" of length
+/// `ttl` instead of storing a `SystemTime` per entry, trading exact precision for
+/// cheap integer comparisons.
+/// ## Example
+/// ```rust
+/// use timed_set::GenerationalTimedSet;
+/// use std::time::Duration;
+///
+/// let mut gs = GenerationalTimedSet::new(Duration::from_millis(100), 1);
+/// gs.add("element_1");
+/// assert!(gs.contains(&"element_1"));
+/// ```
+pub struct GenerationalTimedSet<T> {
+    ttl: Duration,
+    keep: u64,
+    origin: Instant,
+    current_generation: u64,
+    set: HashMap<T, u64>,
+}
+
+impl<T> GenerationalTimedSet<T>
+where
+    T: std::hash::Hash + Eq + Clone,
+{
+    /// Create a new generational set; `keep` is how many past generations an entry
+    /// may live through before it's considered stale.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::GenerationalTimedSet;
+    /// use std::time::Duration;
+    ///
+    /// let gs: GenerationalTimedSet<&str> =
+    ///     GenerationalTimedSet::new(Duration::from_millis(100), 1);
+    /// ```
+    pub fn new(ttl: Duration, keep: u64) -> Self {
+        Self {
+            ttl,
+            keep,
+            origin: Instant::now(),
+            current_generation: 0,
+            set: HashMap::new(),
+        }
+    }
+
+    fn generation_at(&self, instant: Instant) -> u64 {
+        let elapsed = instant.saturating_duration_since(self.origin);
+        let ttl_nanos = self.ttl.as_nanos().max(1);
+        (elapsed.as_nanos() / ttl_nanos) as u64
+    }
+
+    fn advance_generation(&mut self) {
+        self.current_generation = self.generation_at(Instant::now());
+    }
+
+    /// Add/Insert an element, stamping it with the current generation.
+    pub fn add(&mut self, val: T) {
+        self.advance_generation();
+        let generation = self.current_generation;
+        self.set.insert(val, generation);
+    }
+
+    /// Check if an element is present and within `keep` generations of the current one.
+    pub fn contains(&mut self, val: &T) -> bool {
+        self.advance_generation();
+        match self.set.get(val) {
+            Some(&generation) => self.current_generation.saturating_sub(generation) <= self.keep,
+            None => false,
+        }
+    }
+
+    /// Remove all stale-generation entries and return how many were dropped.
+    /// ## Example
+    /// ```rust
+    /// use timed_set::GenerationalTimedSet;
+    /// use std::{time::Duration, thread::sleep};
+    ///
+    /// let mut gs = GenerationalTimedSet::new(Duration::from_millis(50), 0);
+    /// gs.add("element_1");
+    /// sleep(Duration::from_millis(100));
+    /// assert_eq!(gs.prune_generations(), 1);
+    /// ```
+    pub fn prune_generations(&mut self) -> usize {
+        self.advance_generation();
+        let keep = self.keep;
+        let current = self.current_generation;
+        let before = self.set.len();
+        self.set.retain(|_, generation| current.saturating_sub(*generation) <= keep);
+        before - self.set.len()
+    }
+}
+
+/// The expiry schedule backing `TimedSet::with_wheel`. A key in a slot carries no
+/// deadline beyond the slot it was placed in; only overflowed keys keep their
+/// deadline, to be re-placed when the wheel wraps. `TimedSet`'s `HashMap` remains
+/// the source of truth for the exact deadline.
+struct Wheel<T> {
+    slots: Vec<Vec<T>>,
+    overflow: Vec<(T, Instant)>,
+    granularity: Duration,
+    origin: Instant,
+    cursor: u64,
+}
+
+impl<T> Wheel<T> {
+    fn new(slots: usize, granularity: Duration) -> Self {
+        Self {
+            slots: (0..slots.max(1)).map(|_| Vec::new()).collect(),
+            overflow: Vec::new(),
+            granularity,
+            origin: Instant::now(),
+            cursor: 0,
+        }
+    }
+
+    fn tick_index(&self, instant: Instant) -> u64 {
+        let elapsed = instant.saturating_duration_since(self.origin);
+        (elapsed.as_nanos() / self.granularity.as_nanos().max(1)) as u64
+    }
+
+    /// Places `val` in the slot its `deadline` falls into, or the overflow list if
+    /// the deadline is farther out than `granularity * slots`.
+    fn schedule(&mut self, val: T, deadline: Instant) {
+        let slots = self.slots.len() as u64;
+        let target = self.tick_index(deadline);
+        if target >= self.cursor + slots {
+            self.overflow.push((val, deadline));
+        } else {
+            let idx = (target % slots) as usize;
+            self.slots[idx].push(val);
+        }
+    }
+
+    /// Advances `cursor` up to `now`, draining (and returning the keys of) every
+    /// slot whose window has fully elapsed. Re-schedules overflowed entries each
+    /// time the wheel wraps around to the start.
+    fn tick(&mut self, now: Instant) -> Vec<T> {
+        let slots = self.slots.len() as u64;
+        let target = self.tick_index(now);
+        let mut expired = Vec::new();
+
+        // If more than a full revolution has elapsed since the last tick, every
+        // slot's window has unconditionally closed already (the newest slot would
+        // close at `cursor + slots`, and `target` is past that) — fast-forward
+        // straight to the last revolution instead of single-stepping through ticks
+        // whose slots we already know are empty or fully stale. This keeps the cost
+        // proportional to `slots`, not to the elapsed wall-clock gap. Overflowed
+        // entries aren't necessarily due yet, so they're re-scheduled against the
+        // fast-forwarded cursor rather than force-expired.
+        if target > self.cursor + slots {
+            for slot in self.slots.iter_mut() {
+                expired.append(slot);
+            }
+            self.cursor = target - slots;
+            for (val, deadline) in std::mem::take(&mut self.overflow) {
+                self.schedule(val, deadline);
+            }
+        }
+
+        while self.cursor < target {
+            let idx = (self.cursor % slots) as usize;
+            expired.append(&mut self.slots[idx]);
+            self.cursor += 1;
+            if self.cursor.is_multiple_of(slots) {
+                let overflow = std::mem::take(&mut self.overflow);
+                for (val, deadline) in overflow {
+                    self.schedule(val, deadline);
+                }
+            }
+        }
+        expired
+    }
 }
 
 /// Iterator
@@ -216,4 +787,213 @@ mod tests {
         assert!(!ts.contains(&"element_1"));    // expired
         assert!(!ts.contains(&"element_2"));     // expired
     }
+
+    #[test]
+    fn test_with_capacity_evicts_oldest() {
+        let mut ts = TimedSet::with_capacity(Duration::from_secs(5), 2);
+        ts.add("element_1");
+        ts.add("element_2");
+        // pushes the set past its capacity of 2, evicting element_1
+        ts.add("element_3");
+        assert!(!ts.contains(&"element_1"));
+        assert!(ts.contains(&"element_2"));
+        assert!(ts.contains(&"element_3"));
+    }
+
+    #[test]
+    fn test_retain_drops_expired_and_filtered_elements() {
+        let mut ts = TimedSet::new(Duration::from_secs(1));
+        ts.add("keep_1");
+        ts.add("drop_1");
+        ts.add_with_ttl("expired_1", Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(50));
+        ts.retain(|v| v.starts_with("keep"));
+        assert!(ts.contains(&"keep_1"));
+        assert!(!ts.contains(&"drop_1"));
+        assert!(!ts.contains(&"expired_1"));
+    }
+
+    #[test]
+    fn test_generational_timed_set_basic_expiry() {
+        let mut gs = GenerationalTimedSet::new(Duration::from_millis(300), 1);
+        gs.add("element_1");
+        assert!(gs.contains(&"element_1"));
+        // still within 1 kept generation
+        std::thread::sleep(Duration::from_millis(300));
+        assert!(gs.contains(&"element_1"));
+        // now 2+ generations old
+        std::thread::sleep(Duration::from_millis(600));
+        assert!(!gs.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_generational_timed_set_prune_generations() {
+        let mut gs = GenerationalTimedSet::new(Duration::from_millis(100), 0);
+        gs.add("element_1");
+        std::thread::sleep(Duration::from_millis(250));
+        assert_eq!(gs.prune_generations(), 1);
+        assert!(!gs.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_contains_and_refresh_slides_the_window() {
+        let mut ts = TimedSet::new(Duration::from_secs(2));
+        ts.add("element_1");
+        std::thread::sleep(Duration::from_secs(1));
+        assert!(ts.contains_and_refresh(&"element_1"));
+        std::thread::sleep(Duration::from_secs(1));
+        // still alive: the refresh above moved the deadline from 2s to 3s
+        assert!(ts.contains(&"element_1"));
+        assert!(!ts.contains_and_refresh(&"element_2")); // never added
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!ts.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_get_refreshes_and_returns_the_element() {
+        let mut ts = TimedSet::new(Duration::from_secs(2));
+        ts.add("element_1");
+        assert_eq!(ts.get(&"element_1"), Some(&"element_1"));
+        assert_eq!(ts.get(&"element_2"), None);
+        std::thread::sleep(Duration::from_secs(1));
+        assert_eq!(ts.get(&"element_1"), Some(&"element_1"));
+        std::thread::sleep(Duration::from_secs(1));
+        // still alive past the 2s deadline set by the first get: the second get
+        // refreshed it
+        assert_eq!(ts.get(&"element_1"), Some(&"element_1"));
+    }
+
+    #[test]
+    fn test_touch_rearms_with_a_custom_ttl() {
+        let mut ts = TimedSet::new(Duration::from_secs(1));
+        ts.add("element_1");
+        assert!(ts.touch(&"element_1", Duration::from_secs(3)));
+        assert!(!ts.touch(&"element_2", Duration::from_secs(3))); // never added
+        std::thread::sleep(Duration::from_secs(2));
+        // still alive past the original 1s ttl, thanks to the 3s touch
+        assert!(ts.contains(&"element_1"));
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!ts.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_expired_and_prune() {
+        let mut ts = TimedSet::new(Duration::from_secs(1));
+        ts.add("element_1");
+        ts.add_with_ttl("element_2", Duration::from_secs(3));
+        assert_eq!(ts.expired().count(), 0);
+        std::thread::sleep(Duration::from_secs(2));
+        // element_1 has expired but is still physically in the map until pruned
+        let expired: Vec<_> = ts.expired().copied().collect();
+        assert_eq!(expired, vec!["element_1"]);
+        assert_eq!(ts.prune(), 1);
+        assert_eq!(ts.expired().count(), 0);
+        assert!(ts.contains(&"element_2"));
+    }
+
+    #[test]
+    fn test_union_keeps_earlier_deadline() {
+        // a's 1s ttl is earlier than b's 3s one
+        let mut a = TimedSet::new(Duration::from_secs(1));
+        a.add("x");
+        let mut b = TimedSet::new(Duration::from_secs(3));
+        b.add("x");
+        let mut u = a.union(&b);
+        assert!(u.contains(&"x"));
+        std::thread::sleep(Duration::from_secs(2));
+        // if the union had kept b's 3s deadline instead of a's 1s one, this would
+        // still be true
+        assert!(!u.contains(&"x"));
+    }
+
+    #[test]
+    fn test_intersection_keeps_earlier_deadline() {
+        let mut a = TimedSet::new(Duration::from_secs(1));
+        a.add("x");
+        let mut b = TimedSet::new(Duration::from_secs(3));
+        b.add("x");
+        let mut i = a.intersection(&b);
+        assert!(i.contains(&"x"));
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!i.contains(&"x"));
+    }
+
+    #[test]
+    fn test_with_wheel_basic_expiry_and_tick() {
+        let mut ts = TimedSet::with_wheel(Duration::from_secs(2), 4, Duration::from_millis(500));
+        ts.add("element_1");
+        assert!(ts.contains(&"element_1"));
+        std::thread::sleep(Duration::from_secs(3));
+        ts.tick();
+        assert!(!ts.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_with_wheel_refresh_is_rescheduled() {
+        let mut ts = TimedSet::with_wheel(Duration::from_secs(2), 4, Duration::from_millis(500));
+        ts.add("element_1");
+        std::thread::sleep(Duration::from_secs(1));
+        // refresh with a longer ttl before the original 2s deadline elapses
+        assert!(ts.touch(&"element_1", Duration::from_secs(3)));
+        std::thread::sleep(Duration::from_secs(2));
+        // still alive: touch must have rescheduled the wheel entry, not just the
+        // map deadline, or this would have been reaped by the original 2s slot
+        assert!(ts.contains(&"element_1"));
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!ts.contains(&"element_1"));
+    }
+
+    #[test]
+    fn test_with_wheel_fast_forward_does_not_expire_future_overflow_entries() {
+        let mut ts = TimedSet::with_wheel(Duration::from_secs(5), 4, Duration::from_millis(50));
+        ts.add("long_lived");
+        // idle gap far longer than one full revolution (4 * 50ms = 200ms), forcing
+        // the wheel to fast-forward past several revolutions on the next call
+        std::thread::sleep(Duration::from_secs(1));
+        // must still be alive: its real 5s deadline hasn't passed, even though the
+        // overflowed entry had to be rescheduled across the fast-forward
+        assert!(ts.contains(&"long_lived"));
+        std::thread::sleep(Duration::from_secs(5));
+        assert!(!ts.contains(&"long_lived"));
+    }
+
+    #[test]
+    fn test_with_wheel_tick_fast_forwards_instead_of_single_stepping() {
+        let mut ts = TimedSet::with_wheel(Duration::from_millis(5), 4, Duration::from_micros(1));
+        ts.add("element_1");
+        std::thread::sleep(Duration::from_secs(1));
+        let start = Instant::now();
+        ts.tick();
+        // single-stepping through every elapsed microsecond-granularity tick would
+        // take on the order of a second; fast-forwarding should return immediately
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_with_capacity_repeated_insert_does_not_leak_order() {
+        let mut ts = TimedSet::with_capacity(Duration::from_secs(5), 2);
+        // re-adding the same key should never push `set.len()` over capacity, and
+        // must not grow the insertion-order tracking either
+        for _ in 0..10_000 {
+            ts.add("same_key");
+        }
+        assert_eq!(ts.order.len(), 1);
+        assert!(ts.contains(&"same_key"));
+    }
+
+    #[test]
+    fn test_with_capacity_order_does_not_leak_on_ttl_expiry() {
+        let mut ts = TimedSet::with_capacity(Duration::from_millis(1), 1000);
+        // most of these expire by TTL long before the cap of 1000 is ever hit; the
+        // periodic sleeps guarantee `remove_expired` actually has expired entries to
+        // clean up from both `set` and `order` as the loop runs
+        for i in 0..5000 {
+            ts.add(i);
+            if i % 500 == 0 {
+                std::thread::sleep(Duration::from_millis(2));
+            }
+        }
+        assert!(ts.set.len() <= 1000);
+        assert!(ts.order.len() <= 1000);
+    }
 }